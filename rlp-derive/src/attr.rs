@@ -0,0 +1,37 @@
+use syn::{Attribute, Field};
+
+/// Per-field `#[rlp(..)]` behavior.
+#[derive(Default, Clone, Copy)]
+pub struct FieldAttr {
+    /// `#[rlp(skip)]`: the field is not encoded, and is populated with
+    /// `Default::default()` on decode.
+    pub skip: bool,
+    /// `#[rlp(trailing)]`: the field is an optional tail element. It must be
+    /// the last field of the struct, and must only be present in the input
+    /// when there are remaining bytes after decoding the preceding fields.
+    pub trailing: bool,
+}
+
+impl FieldAttr {
+    pub fn from_field(field: &Field) -> Self {
+        let mut attr = Self::default();
+        for meta in field.attrs.iter().filter(|a| is_rlp_attr(a)) {
+            meta.parse_nested_meta(|nested| {
+                if nested.path.is_ident("skip") {
+                    attr.skip = true;
+                } else if nested.path.is_ident("trailing") {
+                    attr.trailing = true;
+                } else {
+                    return Err(nested.error("unrecognized rlp field attribute"));
+                }
+                Ok(())
+            })
+            .expect("invalid #[rlp(..)] attribute");
+        }
+        attr
+    }
+}
+
+fn is_rlp_attr(attr: &Attribute) -> bool {
+    attr.path().is_ident("rlp")
+}
@@ -0,0 +1,209 @@
+use crate::attr::FieldAttr;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields};
+
+pub fn derive_decodable(input: &DeriveInput) -> TokenStream {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    match &input.data {
+        Data::Struct(s) => derive_struct(name, &s.fields, impl_generics, ty_generics, where_clause),
+        Data::Enum(e) => derive_enum(name, e, impl_generics, ty_generics, where_clause),
+        Data::Union(_) => panic!("RlpDecodable cannot be derived for unions"),
+    }
+}
+
+fn derive_struct(
+    name: &syn::Ident,
+    fields: &Fields,
+    impl_generics: syn::ImplGenerics<'_>,
+    ty_generics: syn::TypeGenerics<'_>,
+    where_clause: Option<&syn::WhereClause>,
+) -> TokenStream {
+    let attrs: Vec<_> = fields.iter().map(FieldAttr::from_field).collect();
+    let count = fields.len();
+    let trailing_idx = attrs.iter().position(|a| a.trailing);
+    if let Some(idx) = trailing_idx {
+        assert_eq!(idx, count.saturating_sub(1), "#[rlp(trailing)] is only allowed on the last field");
+    }
+
+    let expected = attrs.iter().filter(|a| !a.skip && !a.trailing).count();
+
+    let field_decoders = fields.iter().zip(&attrs).enumerate().map(|(i, (field, attr))| {
+        let ty = &field.ty;
+        let binding = binding_ident(field, i);
+        if attr.skip {
+            quote! { let #binding: #ty = ::core::default::Default::default(); }
+        } else if attr.trailing {
+            quote! {
+                let #binding: #ty = if buf.is_empty() {
+                    ::core::default::Default::default()
+                } else {
+                    ::rlp::Decodable::decode(buf)?
+                };
+            }
+        } else {
+            let decoded = attrs[..i].iter().filter(|a| !a.skip).count();
+            quote! {
+                if buf.is_empty() {
+                    return Err(::rlp::error::Error::ListLengthMismatch(#expected, #decoded));
+                }
+                let #binding: #ty = ::rlp::Decodable::decode(buf)?;
+            }
+        }
+    });
+
+    let construct = construct_expr(quote! { Self }, fields);
+
+    quote! {
+        impl #impl_generics ::rlp::decode::Decodable for #name #ty_generics #where_clause {
+            fn decode(buf: &mut &[u8]) -> ::rlp::error::Result<Self> {
+                let payload = ::rlp::header::Header::decode_bytes(buf, true)?;
+                let buf = &mut &*payload;
+
+                #(#field_decoders)*
+
+                if !buf.is_empty() {
+                    return Err(::rlp::error::Error::ListLengthMismatch(0, buf.len()));
+                }
+
+                Ok(#construct)
+            }
+        }
+    }
+}
+
+fn derive_enum(
+    name: &syn::Ident,
+    data: &syn::DataEnum,
+    impl_generics: syn::ImplGenerics<'_>,
+    ty_generics: syn::TypeGenerics<'_>,
+    where_clause: Option<&syn::WhereClause>,
+) -> TokenStream {
+    let arms = data.variants.iter().enumerate().map(|(i, variant)| {
+        let v_ident = &variant.ident;
+        let discriminant = i as u64;
+        let expected = variant.fields.len();
+        let field_decoders = variant.fields.iter().enumerate().map(|(i, field)| {
+            let ty = &field.ty;
+            let binding = binding_ident(field, i);
+            quote! {
+                if buf.is_empty() {
+                    return Err(::rlp::error::Error::ListLengthMismatch(#expected, #i));
+                }
+                let #binding: #ty = ::rlp::Decodable::decode(buf)?;
+            }
+        });
+        let construct = construct_expr(quote! { Self::#v_ident }, &variant.fields);
+
+        quote! {
+            #discriminant => {
+                #(#field_decoders)*
+                #construct
+            }
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::rlp::decode::Decodable for #name #ty_generics #where_clause {
+            fn decode(buf: &mut &[u8]) -> ::rlp::error::Result<Self> {
+                let payload = ::rlp::header::Header::decode_bytes(buf, true)?;
+                let buf = &mut &*payload;
+
+                let discriminant: u64 = ::rlp::Decodable::decode(buf)?;
+                let value = match discriminant {
+                    #(#arms)*
+                    _ => return Err(::rlp::error::Error::UnexpectedLength),
+                };
+
+                if !buf.is_empty() {
+                    return Err(::rlp::error::Error::ListLengthMismatch(0, buf.len()));
+                }
+
+                Ok(value)
+            }
+        }
+    }
+}
+
+fn binding_ident(field: &syn::Field, index: usize) -> syn::Ident {
+    match &field.ident {
+        Some(ident) => ident.clone(),
+        None => quote::format_ident!("f{}", index),
+    }
+}
+
+fn construct_expr(path: TokenStream, fields: &Fields) -> TokenStream {
+    match fields {
+        Fields::Named(f) => {
+            let idents = f.named.iter().map(|f| f.ident.as_ref().unwrap());
+            quote! { #path { #(#idents),* } }
+        }
+        Fields::Unnamed(f) => {
+            let binds: Vec<_> = (0..f.unnamed.len()).map(|i| quote::format_ident!("f{}", i)).collect();
+            quote! { #path(#(#binds),*) }
+        }
+        Fields::Unit => quote! { #path },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::ToTokens;
+
+    fn derive(src: &str) -> syn::ItemImpl {
+        let input: DeriveInput = syn::parse_str(src).unwrap();
+        let generated = derive_decodable(&input);
+        syn::parse2(generated).expect("derive_decodable must emit a single valid impl block")
+    }
+
+    #[test]
+    fn named_struct_decodes_every_field_in_order() {
+        let generated = derive("struct Foo { a: u8, b: u16 }").to_token_stream().to_string();
+        let a_pos = generated.find("let a : u8").unwrap();
+        let b_pos = generated.find("let b : u16").unwrap();
+        assert!(a_pos < b_pos, "fields must be decoded in declaration order");
+        assert!(generated.contains("Self { a , b }"));
+    }
+
+    #[test]
+    fn tuple_struct_uses_positional_bindings() {
+        let generated = derive("struct Foo(u8, u16);").to_token_stream().to_string();
+        assert!(generated.contains("let f0 : u8"));
+        assert!(generated.contains("let f1 : u16"));
+        assert!(generated.contains("Self (f0 , f1)"));
+    }
+
+    #[test]
+    fn skipped_field_defaults_instead_of_decoding() {
+        let generated =
+            derive("struct Foo { a: u8, #[rlp(skip)] b: u16 }").to_token_stream().to_string();
+        assert!(generated.contains("let a : u8 = :: rlp :: Decodable :: decode (buf) ?"));
+        assert!(generated.contains("let b : u16 = :: core :: default :: Default :: default ()"));
+        assert!(!generated.contains("let b : u16 = :: rlp :: Decodable :: decode (buf) ?"));
+    }
+
+    #[test]
+    fn trailing_field_is_conditional_on_remaining_bytes() {
+        let generated =
+            derive("struct Foo { a: u8, #[rlp(trailing)] b: Option<u16> }").to_token_stream().to_string();
+        assert!(generated.contains("if buf . is_empty ()"));
+        assert!(generated.contains("let b : Option < u16 >"));
+    }
+
+    #[test]
+    #[should_panic(expected = "#[rlp(trailing)] is only allowed on the last field")]
+    fn trailing_field_must_be_last() {
+        derive("struct Foo { #[rlp(trailing)] a: Option<u8>, b: u16 }");
+    }
+
+    #[test]
+    fn enum_matches_on_variant_discriminant() {
+        let generated = derive("enum Foo { A, B(u8) }").to_token_stream().to_string();
+        assert!(generated.contains("0u64 =>"));
+        assert!(generated.contains("1u64 =>"));
+        assert!(generated.contains("Self :: B (f0)"));
+    }
+}
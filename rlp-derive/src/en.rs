@@ -0,0 +1,197 @@
+use crate::attr::FieldAttr;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Index};
+
+pub fn derive_encodable(input: &DeriveInput) -> TokenStream {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    match &input.data {
+        Data::Struct(s) => derive_struct(name, &s.fields, impl_generics, ty_generics, where_clause),
+        Data::Enum(e) => derive_enum(name, e, impl_generics, ty_generics, where_clause),
+        Data::Union(_) => panic!("RlpEncodable cannot be derived for unions"),
+    }
+}
+
+fn derive_struct(
+    name: &syn::Ident,
+    fields: &Fields,
+    impl_generics: syn::ImplGenerics<'_>,
+    ty_generics: syn::TypeGenerics<'_>,
+    where_clause: Option<&syn::WhereClause>,
+) -> TokenStream {
+    let field_idents = field_accessors(fields);
+    let attrs: Vec<_> = fields.iter().map(FieldAttr::from_field).collect();
+
+    let encoded_fields: Vec<_> = field_idents
+        .iter()
+        .zip(&attrs)
+        .filter(|(_, attr)| !attr.skip)
+        .map(|(f, _)| f)
+        .collect();
+
+    let lengths: Vec<_> =
+        encoded_fields.iter().map(|f| quote! { ::rlp::Encodable::length(&self.#f) }).collect();
+    let encodes: Vec<_> =
+        encoded_fields.iter().map(|f| quote! { ::rlp::Encodable::encode(&self.#f, out); }).collect();
+
+    quote! {
+        impl #impl_generics ::rlp::encode::Encodable for #name #ty_generics #where_clause {
+            fn length(&self) -> usize {
+                let payload_length = 0usize #(+ #lengths)*;
+                payload_length + ::rlp::header::length_of_length(payload_length)
+            }
+
+            fn encode(&self, out: &mut dyn ::rlp::bytes::BufMut) {
+                let payload_length = 0usize #(+ #lengths)*;
+                ::rlp::header::Header::new(true, payload_length).encode(out);
+                #(#encodes)*
+            }
+        }
+    }
+}
+
+fn derive_enum(
+    name: &syn::Ident,
+    data: &syn::DataEnum,
+    impl_generics: syn::ImplGenerics<'_>,
+    ty_generics: syn::TypeGenerics<'_>,
+    where_clause: Option<&syn::WhereClause>,
+) -> TokenStream {
+    let arms = data.variants.iter().enumerate().map(|(i, variant)| {
+        let v_ident = &variant.ident;
+        let discriminant = i as u64;
+        let (pattern, field_idents) = match &variant.fields {
+            Fields::Unit => (quote! { Self::#v_ident }, vec![]),
+            Fields::Unnamed(f) => {
+                let binds: Vec<_> = (0..f.unnamed.len())
+                    .map(|i| quote::format_ident!("f{}", i))
+                    .collect();
+                (quote! { Self::#v_ident(#(#binds),*) }, binds)
+            }
+            Fields::Named(f) => {
+                let binds: Vec<_> = f.named.iter().map(|f| f.ident.clone().unwrap()).collect();
+                (quote! { Self::#v_ident { #(#binds),* } }, binds)
+            }
+        };
+
+        let lengths = field_idents
+            .iter()
+            .map(|f| quote! { ::rlp::Encodable::length(#f) });
+        let encodes = field_idents
+            .iter()
+            .map(|f| quote! { ::rlp::Encodable::encode(#f, out); });
+
+        (pattern, discriminant, lengths.collect::<Vec<_>>(), encodes.collect::<Vec<_>>())
+    });
+
+    let length_arms = arms.clone().map(|(pattern, discriminant, lengths, _)| {
+        quote! {
+            #pattern => {
+                let payload_length = ::rlp::Encodable::length(&#discriminant) #(+ #lengths)*;
+                payload_length + ::rlp::header::length_of_length(payload_length)
+            }
+        }
+    });
+
+    let encode_arms = arms.map(|(pattern, discriminant, lengths, encodes)| {
+        quote! {
+            #pattern => {
+                let payload_length = ::rlp::Encodable::length(&#discriminant) #(+ #lengths)*;
+                ::rlp::header::Header::new(true, payload_length).encode(out);
+                ::rlp::Encodable::encode(&#discriminant, out);
+                #(#encodes)*
+            }
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::rlp::encode::Encodable for #name #ty_generics #where_clause {
+            fn length(&self) -> usize {
+                match self {
+                    #(#length_arms)*
+                }
+            }
+
+            fn encode(&self, out: &mut dyn ::rlp::bytes::BufMut) {
+                match self {
+                    #(#encode_arms)*
+                }
+            }
+        }
+    }
+}
+
+/// Returns the accessor tokens (`0`, `1`, ... or field names) for every field
+/// in declaration order, matching the order fields are decoded in.
+fn field_accessors(fields: &Fields) -> Vec<TokenStream> {
+    match fields {
+        Fields::Named(f) => f
+            .named
+            .iter()
+            .map(|f| {
+                let ident = f.ident.as_ref().unwrap();
+                quote! { #ident }
+            })
+            .collect(),
+        Fields::Unnamed(f) => (0..f.unnamed.len())
+            .map(|i| {
+                let idx = Index::from(i);
+                quote! { #idx }
+            })
+            .collect(),
+        Fields::Unit => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::ToTokens;
+    use syn::DeriveInput;
+
+    fn derive(src: &str) -> syn::ItemImpl {
+        let input: DeriveInput = syn::parse_str(src).unwrap();
+        let generated = derive_encodable(&input);
+        syn::parse2(generated).expect("derive_encodable must emit a single valid impl block")
+    }
+
+    #[test]
+    fn named_struct_encodes_every_field_in_order() {
+        let generated = derive("struct Foo { a: u8, b: u16 }").to_token_stream().to_string();
+        let a_pos = generated.find("self . a").unwrap();
+        let b_pos = generated.find("self . b").unwrap();
+        assert!(a_pos < b_pos, "fields must be encoded in declaration order");
+    }
+
+    #[test]
+    fn tuple_struct_uses_index_accessors() {
+        let generated = derive("struct Foo(u8, u16);").to_token_stream().to_string();
+        assert!(generated.contains("self . 0"));
+        assert!(generated.contains("self . 1"));
+    }
+
+    #[test]
+    fn unit_struct_encodes_an_empty_list() {
+        let generated = derive("struct Foo;").to_token_stream().to_string();
+        assert!(generated.contains("Header :: new (true , payload_length)"));
+        assert!(!generated.contains("self ."));
+    }
+
+    #[test]
+    fn skipped_field_is_not_encoded() {
+        let generated =
+            derive("struct Foo { a: u8, #[rlp(skip)] b: u16 }").to_token_stream().to_string();
+        assert!(generated.contains("self . a"));
+        assert!(!generated.contains("self . b"));
+    }
+
+    #[test]
+    fn enum_matches_on_variant_discriminant() {
+        let generated =
+            derive("enum Foo { A, B(u8) }").to_token_stream().to_string();
+        assert!(generated.contains("Self :: A"));
+        assert!(generated.contains("Self :: B (f0)"));
+    }
+}
@@ -0,0 +1,35 @@
+//! Derive macros for `rlp`'s `Encodable`/`Decodable` traits.
+//!
+//! `#[derive(RlpEncodable)]` emits an impl that writes a list header
+//! (`Header::new(true, ..)`) followed by each field's own encoding, in
+//! declaration order. `#[derive(RlpDecodable)]` mirrors this: it reads the
+//! list header via `Header::decode_bytes(buf, true)`, then decodes each
+//! field from the inner payload, erroring with `Error::ListLengthMismatch`
+//! if bytes are left over or run out early.
+//!
+//! Fields may be annotated with:
+//! - `#[rlp(skip)]` — not encoded; filled with `Default::default()` on decode.
+//! - `#[rlp(trailing)]` — an optional tail field, only decoded when bytes
+//!   remain after the preceding fields (must be the last field).
+//!
+//! Enums are supported by encoding the variant's index as the first list
+//! element, followed by that variant's fields.
+
+mod attr;
+mod de;
+mod en;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+#[proc_macro_derive(RlpEncodable, attributes(rlp))]
+pub fn derive_rlp_encodable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    en::derive_encodable(&input).into()
+}
+
+#[proc_macro_derive(RlpDecodable, attributes(rlp))]
+pub fn derive_rlp_decodable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    de::derive_decodable(&input).into()
+}
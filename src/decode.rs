@@ -1,6 +1,14 @@
 use crate::{copy_from_slice, error::{Error, Result}, header::Header};
+#[cfg(feature = "bytes")]
 use bytes::{Bytes, BytesMut};
 use core::marker::{PhantomData, PhantomPinned};
+#[cfg(feature = "alloc")]
+use alloc::{collections::{BTreeMap, BTreeSet, LinkedList, VecDeque}, string::String};
+#[cfg(feature = "alloc")]
+#[allow(unused_imports)]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
 
 pub trait Decodable: Sized {
     fn decode(buf: &mut &[u8]) -> Result<Self>;
@@ -73,6 +81,30 @@ macro_rules! decode_integer {
 
 decode_integer!(u8, u16, u32, u64, usize, u128);
 
+// Reuses the underlying integer's decode path, then rejects a decoded zero
+// so the niche-optimized `NonZero*` types round-trip safely.
+macro_rules! decode_nonzero {
+    ($($nz:ty => $prim:ty),+ $(,)?) => {$(
+        impl Decodable for $nz {
+            #[inline]
+            fn decode(buf: &mut &[u8]) -> Result<Self> {
+                let value = <$prim>::decode(buf)?;
+                Self::new(value).ok_or(Error::Zero)
+            }
+        }
+    )+};
+}
+
+decode_nonzero!(
+    core::num::NonZeroU8 => u8,
+    core::num::NonZeroU16 => u16,
+    core::num::NonZeroU32 => u32,
+    core::num::NonZeroU64 => u64,
+    core::num::NonZeroUsize => usize,
+    core::num::NonZeroU128 => u128,
+);
+
+#[cfg(feature = "bytes")]
 impl Decodable for Bytes {
     #[inline]
     fn decode(buf: &mut &[u8]) -> Result<Self> {
@@ -80,6 +112,7 @@ impl Decodable for Bytes {
     }
 }
 
+#[cfg(feature = "bytes")]
 impl Decodable for BytesMut {
     #[inline]
     fn decode(buf: &mut &[u8]) -> Result<Self> {
@@ -88,6 +121,7 @@ impl Decodable for BytesMut {
 }
 
 
+#[cfg(feature = "alloc")]
 impl Decodable for String {
     #[inline]
     fn decode(buf: &mut &[u8]) -> Result<Self> {
@@ -95,6 +129,7 @@ impl Decodable for String {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T: Decodable> Decodable for Vec<T> {
     #[inline]
     fn decode(buf: &mut &[u8]) -> Result<Self> {
@@ -107,6 +142,115 @@ impl<T: Decodable> Decodable for Vec<T> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<T: Decodable> Decodable for VecDeque<T> {
+    #[inline]
+    fn decode(buf: &mut &[u8]) -> Result<Self> {
+        let mut bytes = Header::decode_bytes(buf, true)?;
+        let mut deque = Self::new();
+        while !bytes.is_empty() {
+            deque.push_back(T::decode(&mut bytes)?);
+        }
+        Ok(deque)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Decodable> Decodable for LinkedList<T> {
+    #[inline]
+    fn decode(buf: &mut &[u8]) -> Result<Self> {
+        let mut bytes = Header::decode_bytes(buf, true)?;
+        let mut list = Self::new();
+        while !bytes.is_empty() {
+            list.push_back(T::decode(&mut bytes)?);
+        }
+        Ok(list)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Decodable + Ord> Decodable for BTreeSet<T> {
+    #[inline]
+    fn decode(buf: &mut &[u8]) -> Result<Self> {
+        let mut bytes = Header::decode_bytes(buf, true)?;
+        let mut set = Self::new();
+        while !bytes.is_empty() {
+            set.insert(T::decode(&mut bytes)?);
+        }
+        Ok(set)
+    }
+}
+
+// Maps are decoded from the flat `[k0, v0, k1, v1, ...]` layout documented
+// on the `Encodable` impls in `encode.rs`: an odd number of elements means a
+// key with no matching value, reported via `Error::ListLengthMismatch`.
+#[cfg(feature = "alloc")]
+impl<K: Decodable + Ord, V: Decodable> Decodable for BTreeMap<K, V> {
+    #[inline]
+    fn decode(buf: &mut &[u8]) -> Result<Self> {
+        let mut bytes = Header::decode_bytes(buf, true)?;
+        let mut map = Self::new();
+        while !bytes.is_empty() {
+            let key = K::decode(&mut bytes)?;
+            if bytes.is_empty() {
+                return Err(Error::ListLengthMismatch(2, 1));
+            }
+            let value = V::decode(&mut bytes)?;
+            map.insert(key, value);
+        }
+        Ok(map)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Decodable + Eq + core::hash::Hash, V: Decodable> Decodable for HashMap<K, V> {
+    #[inline]
+    fn decode(buf: &mut &[u8]) -> Result<Self> {
+        let mut bytes = Header::decode_bytes(buf, true)?;
+        let mut map = Self::new();
+        while !bytes.is_empty() {
+            let key = K::decode(&mut bytes)?;
+            if bytes.is_empty() {
+                return Err(Error::ListLengthMismatch(2, 1));
+            }
+            let value = V::decode(&mut bytes)?;
+            map.insert(key, value);
+        }
+        Ok(map)
+    }
+}
+
+// Tuples decode positionally from a fixed-length RLP list; any bytes left
+// over after the declared arity is consumed means the encoded list was
+// longer than expected.
+macro_rules! impl_tuple_decode {
+    ($($T:ident),+ $(,)?) => {
+        impl<$($T: Decodable),+> Decodable for ($($T,)+) {
+            #[inline]
+            fn decode(buf: &mut &[u8]) -> Result<Self> {
+                let mut bytes = Header::decode_bytes(buf, true)?;
+                let value = ($($T::decode(&mut bytes)?,)+);
+                if !bytes.is_empty() {
+                    return Err(Error::ListLengthMismatch(0, bytes.len()));
+                }
+                Ok(value)
+            }
+        }
+    };
+}
+
+impl_tuple_decode!(A);
+impl_tuple_decode!(A, B);
+impl_tuple_decode!(A, B, C);
+impl_tuple_decode!(A, B, C, D);
+impl_tuple_decode!(A, B, C, D, E);
+impl_tuple_decode!(A, B, C, D, E, F);
+impl_tuple_decode!(A, B, C, D, E, F, G);
+impl_tuple_decode!(A, B, C, D, E, F, G, H);
+impl_tuple_decode!(A, B, C, D, E, F, G, H, I);
+impl_tuple_decode!(A, B, C, D, E, F, G, H, I, J);
+impl_tuple_decode!(A, B, C, D, E, F, G, H, I, J, K);
+impl_tuple_decode!(A, B, C, D, E, F, G, H, I, J, K, L);
 
 #[inline]
 pub fn decode_exact<T: Decodable>(bytes: impl AsRef<[u8]>) -> Result<T> {
@@ -134,4 +278,57 @@ pub(crate) fn static_left_pad<const N: usize>(data: &[u8]) -> Result<[u8; N]> {
         copy_from_slice(&mut v[N - data.len()..], data);
     }
     Ok(v)
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod tests {
+    use super::*;
+    use crate::encode::encode;
+
+    #[test]
+    fn roundtrip_vecdeque() {
+        let empty: VecDeque<u64> = VecDeque::new();
+        assert_eq!(decode_exact::<VecDeque<u64>>(encode(empty.clone())).unwrap(), empty);
+
+        let deque: VecDeque<u64> = VecDeque::from([1, 2, 3]);
+        assert_eq!(decode_exact::<VecDeque<u64>>(encode(deque.clone())).unwrap(), deque);
+    }
+
+    #[test]
+    fn roundtrip_linked_list() {
+        let empty: LinkedList<u64> = LinkedList::new();
+        assert_eq!(decode_exact::<LinkedList<u64>>(encode(empty.clone())).unwrap(), empty);
+
+        let list: LinkedList<u64> = LinkedList::from([1, 2, 3]);
+        assert_eq!(decode_exact::<LinkedList<u64>>(encode(list.clone())).unwrap(), list);
+    }
+
+    #[test]
+    fn roundtrip_btree_set() {
+        let empty: BTreeSet<u64> = BTreeSet::new();
+        assert_eq!(decode_exact::<BTreeSet<u64>>(encode(empty.clone())).unwrap(), empty);
+
+        let set: BTreeSet<u64> = BTreeSet::from([1, 2, 3]);
+        assert_eq!(decode_exact::<BTreeSet<u64>>(encode(set.clone())).unwrap(), set);
+    }
+
+    #[test]
+    fn roundtrip_btree_map() {
+        let empty: BTreeMap<u64, u64> = BTreeMap::new();
+        assert_eq!(decode_exact::<BTreeMap<u64, u64>>(encode(empty.clone())).unwrap(), empty);
+
+        let map: BTreeMap<u64, u64> = BTreeMap::from([(1, 10), (2, 20)]);
+        assert_eq!(decode_exact::<BTreeMap<u64, u64>>(encode(map.clone())).unwrap(), map);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn roundtrip_hash_map() {
+        let empty: HashMap<u64, u64> = HashMap::new();
+        assert_eq!(decode_exact::<HashMap<u64, u64>>(encode(empty.clone())).unwrap(), empty);
+
+        let map: HashMap<u64, u64> = HashMap::from([(1, 10), (2, 20)]);
+        assert_eq!(decode_exact::<HashMap<u64, u64>>(encode(map.clone())).unwrap(), map);
+    }
 }
\ No newline at end of file
@@ -1,22 +1,68 @@
 use crate::header::{Header, EMPTY_STRING_CODE, length_of_length};
-use bytes::{BufMut, Bytes, BytesMut};
+use bytes::BufMut;
+#[cfg(feature = "bytes")]
+use bytes::{Bytes, BytesMut};
 use core::{
      borrow::Borrow, marker::{PhantomData, PhantomPinned}
 };
 
-use std::{borrow::Cow, rc::Rc, sync::Arc};
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, borrow::{Cow, ToOwned}, rc::Rc, string::String, sync::Arc};
+#[cfg(feature = "alloc")]
+use alloc::collections::{BTreeMap, BTreeSet, LinkedList, VecDeque};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "alloc")]
 #[allow(unused_imports)]
-use std::vec::Vec;
+use alloc::vec::Vec;
 
 pub trait Encodable {
     fn encode(&self, out: &mut dyn BufMut);
 
     /// Returns the length of the encoding in bytes.
+    ///
+    /// The default implementation runs `encode` against a [`LengthCounter`]
+    /// that only tallies bytes instead of storing them, so computing a
+    /// length never allocates.
     #[inline]
     fn length(&self) -> usize {
-        let mut out = Vec::new();
-        self.encode(&mut out);
-        out.len()
+        let mut counter = LengthCounter(0);
+        self.encode(&mut counter);
+        counter.0
+    }
+}
+
+/// A [`BufMut`] sink that counts bytes without storing them.
+///
+/// Backs the default [`Encodable::length`]. Only `put_u8`/`put_slice` are
+/// overridden to track the count directly; `Encodable::encode` never calls
+/// any other `BufMut` method, so `chunk_mut` is unreachable in practice.
+struct LengthCounter(usize);
+
+unsafe impl BufMut for LengthCounter {
+    #[inline]
+    fn remaining_mut(&self) -> usize {
+        usize::MAX - self.0
+    }
+
+    #[inline]
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        self.0 += cnt;
+    }
+
+    #[inline]
+    fn chunk_mut(&mut self) -> &mut bytes::buf::UninitSlice {
+        unreachable!("LengthCounter only supports put_u8/put_slice")
+    }
+
+    #[inline]
+    fn put_slice(&mut self, src: &[u8]) {
+        self.0 += src.len();
+    }
+
+    #[inline]
+    fn put_u8(&mut self, _val: u8) {
+        self.0 += 1;
     }
 }
 
@@ -81,6 +127,34 @@ macro_rules! to_be_bytes_trimmed {
 
 impl_uint!(u8, u16, u32, u64, usize, u128);
 
+// `NonZero*` reuse the integer encoding above via `get()`; the zero check
+// they need happens only on decode (see `decode.rs`), since every nonzero
+// value encodes identically to its underlying primitive.
+macro_rules! impl_nonzero {
+    ($($t:ty),+ $(,)?) => {$(
+        impl Encodable for $t {
+            #[inline]
+            fn length(&self) -> usize {
+                self.get().length()
+            }
+
+            #[inline]
+            fn encode(&self, out: &mut dyn BufMut) {
+                self.get().encode(out)
+            }
+        }
+    )+};
+}
+
+impl_nonzero!(
+    core::num::NonZeroU8,
+    core::num::NonZeroU16,
+    core::num::NonZeroU32,
+    core::num::NonZeroU64,
+    core::num::NonZeroUsize,
+    core::num::NonZeroU128,
+);
+
 // Implement for slices and basic types
 impl Encodable for [u8] {
     #[inline]
@@ -101,6 +175,20 @@ impl Encodable for [u8] {
     }
 }
 
+// Symmetric with `Decodable for [u8; N]` in `decode.rs`: encoded as a plain
+// RLP string of exactly N bytes, with no big-endian trimming.
+impl<const N: usize> Encodable for [u8; N] {
+    #[inline]
+    fn length(&self) -> usize {
+        self.as_slice().length()
+    }
+
+    #[inline]
+    fn encode(&self, out: &mut dyn BufMut) {
+        self.as_slice().encode(out)
+    }
+}
+
 impl Encodable for str {
     #[inline]
     fn length(&self) -> usize {
@@ -113,6 +201,7 @@ impl Encodable for str {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T: Encodable> Encodable for Vec<T> {
     #[inline]
     fn length(&self) -> usize {
@@ -125,6 +214,133 @@ impl<T: Encodable> Encodable for Vec<T> {
     }
 }
 
+// `VecDeque`/`LinkedList`/`BTreeSet` aren't contiguous slices, so they can't
+// go through `encode_list`/`list_length` (which take `&[B]`); `encode_iter`
+// only needs `Iterator + Clone`, which their `iter()` already provides.
+//
+// Note: we deliberately do *not* add a blanket `impl<T: Encodable> Encodable
+// for [T]`/`[T; N]`. That would overlap with the existing `[u8]`/`[u8; N]`
+// impls above, which encode as RLP byte strings rather than as a list of
+// single-byte items, and Rust has no specialization on stable to let both
+// coexist. Callers that need a generic array-as-list should go through
+// `Vec<T>` or call `encode_list`/`list_length` directly.
+#[cfg(feature = "alloc")]
+impl<T: Encodable> Encodable for VecDeque<T> {
+    #[inline]
+    fn length(&self) -> usize {
+        let payload_length: usize = self.iter().map(Encodable::length).sum();
+        payload_length + length_of_length(payload_length)
+    }
+
+    #[inline]
+    fn encode(&self, out: &mut dyn BufMut) {
+        encode_iter::<_, &T, T>(self.iter(), out)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Encodable> Encodable for LinkedList<T> {
+    #[inline]
+    fn length(&self) -> usize {
+        let payload_length: usize = self.iter().map(Encodable::length).sum();
+        payload_length + length_of_length(payload_length)
+    }
+
+    #[inline]
+    fn encode(&self, out: &mut dyn BufMut) {
+        encode_iter::<_, &T, T>(self.iter(), out)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Encodable> Encodable for BTreeSet<T> {
+    #[inline]
+    fn length(&self) -> usize {
+        let payload_length: usize = self.iter().map(Encodable::length).sum();
+        payload_length + length_of_length(payload_length)
+    }
+
+    #[inline]
+    fn encode(&self, out: &mut dyn BufMut) {
+        encode_iter::<_, &T, T>(self.iter(), out)
+    }
+}
+
+// Maps are encoded as a flat RLP list of alternating key/value items, i.e.
+// `[k0, v0, k1, v1, ...]` rather than a list of 2-element sublists. Decoders
+// must know this invariant to reconstruct pairs; see the `Decodable` impls
+// in `decode.rs`.
+#[cfg(feature = "alloc")]
+impl<K: Encodable, V: Encodable> Encodable for BTreeMap<K, V> {
+    #[inline]
+    fn length(&self) -> usize {
+        let payload_length: usize = self.iter().map(|(k, v)| k.length() + v.length()).sum();
+        payload_length + length_of_length(payload_length)
+    }
+
+    #[inline]
+    fn encode(&self, out: &mut dyn BufMut) {
+        let payload_length: usize = self.iter().map(|(k, v)| k.length() + v.length()).sum();
+        Header::new(true, payload_length).encode(out);
+        for (k, v) in self.iter() {
+            k.encode(out);
+            v.encode(out);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Encodable, V: Encodable> Encodable for HashMap<K, V> {
+    #[inline]
+    fn length(&self) -> usize {
+        let payload_length: usize = self.iter().map(|(k, v)| k.length() + v.length()).sum();
+        payload_length + length_of_length(payload_length)
+    }
+
+    #[inline]
+    fn encode(&self, out: &mut dyn BufMut) {
+        let payload_length: usize = self.iter().map(|(k, v)| k.length() + v.length()).sum();
+        Header::new(true, payload_length).encode(out);
+        for (k, v) in self.iter() {
+            k.encode(out);
+            v.encode(out);
+        }
+    }
+}
+
+// Tuples encode as a fixed-length RLP list of their elements.
+macro_rules! impl_tuple {
+    ($($idx:tt $T:ident),+ $(,)?) => {
+        impl<$($T: Encodable),+> Encodable for ($($T,)+) {
+            #[inline]
+            fn length(&self) -> usize {
+                let payload_length = 0usize $(+ self.$idx.length())+;
+                payload_length + length_of_length(payload_length)
+            }
+
+            #[inline]
+            fn encode(&self, out: &mut dyn BufMut) {
+                let payload_length = 0usize $(+ self.$idx.length())+;
+                Header::new(true, payload_length).encode(out);
+                $(self.$idx.encode(out);)+
+            }
+        }
+    };
+}
+
+impl_tuple!(0 A);
+impl_tuple!(0 A, 1 B);
+impl_tuple!(0 A, 1 B, 2 C);
+impl_tuple!(0 A, 1 B, 2 C, 3 D);
+impl_tuple!(0 A, 1 B, 2 C, 3 D, 4 E);
+impl_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+impl_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+impl_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
+impl_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I);
+impl_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J);
+impl_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K);
+impl_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K, 11 L);
+
 // Implement for wrapper types
 macro_rules! impl_wrapper {
     ($($(#[$attr:meta])* [$($gen:tt)*] $t:ty),+ $(,)?) => {$(
@@ -144,14 +360,21 @@ macro_rules! impl_wrapper {
 }
 
 impl_wrapper! {
+    #[cfg(feature = "alloc")]
     [] String,
+    #[cfg(feature = "bytes")]
     [] Bytes,
+    #[cfg(feature = "bytes")]
     [] BytesMut,
     [T: ?Sized + Encodable] &T,
     [T: ?Sized + Encodable] &mut T,
+    #[cfg(feature = "alloc")]
     [T: ?Sized + Encodable] Box<T>,
+    #[cfg(feature = "alloc")]
     [T: ?Sized + Encodable] Rc<T>,
+    #[cfg(feature = "alloc")]
     [T: ?Sized + Encodable] Arc<T>,
+    #[cfg(feature = "alloc")]
     [T: ?Sized + ToOwned + Encodable] Cow<'_, T>,
 }
 
@@ -170,6 +393,7 @@ impl Encodable for PhantomPinned {
     fn encode(&self, _out: &mut dyn BufMut) {}
 }
 
+#[cfg(feature = "alloc")]
 #[inline]
 pub fn encode<T: Encodable>(value: T) -> Vec<u8> {
     let mut out = Vec::with_capacity(value.length());
@@ -197,7 +421,7 @@ where
     B: Borrow<T>,
     T: ?Sized + Encodable,
 {
-    let mut h = Header { packed: 0 };
+    let mut h = Header::new(true, 0);
     for t in values.clone() {
         h = Header::new(true, h.payload_length() + t.borrow().length());
     }
@@ -235,6 +459,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec;
     use hex_literal::hex;
 
     #[test]
@@ -251,9 +476,48 @@ mod tests {
         assert_eq!(encode("test"), hex!("8474657374"));
     }
 
+    #[test]
+    fn encode_fixed_arrays() {
+        assert_eq!(encode([0u8; 0]), hex!("80"));
+        assert_eq!(encode([0x7Fu8]), hex!("7F"));
+        assert_eq!(encode([0xFFu8, 0xFFu8]), hex!("82ffff"));
+    }
+
     #[test]
     fn encode_lists() {
         assert_eq!(encode(Vec::<u8>::new()), hex!("c0"));
         assert_eq!(encode(vec![0xFFu8, 0xFFu8]), hex!("c481ff81ff"));
     }
+
+    // `encode_iter` backs `VecDeque`/`LinkedList`/`BTreeSet`; an empty
+    // collection must still encode as an empty *list* (`0xc0`), not an
+    // empty string (`0x80`).
+    #[test]
+    fn encode_iter_collections() {
+        assert_eq!(encode(VecDeque::<u8>::new()), hex!("c0"));
+        assert_eq!(encode(VecDeque::from([0xFFu8, 0xFFu8])), hex!("c481ff81ff"));
+
+        assert_eq!(encode(LinkedList::<u8>::new()), hex!("c0"));
+        assert_eq!(encode(LinkedList::from([0xFFu8, 0xFFu8])), hex!("c481ff81ff"));
+
+        assert_eq!(encode(BTreeSet::<u8>::new()), hex!("c0"));
+        assert_eq!(encode(BTreeSet::from([0xFFu8])), hex!("c281ff"));
+    }
+
+    #[test]
+    fn encode_maps() {
+        assert_eq!(encode(BTreeMap::<u8, u8>::new()), hex!("c0"));
+        assert_eq!(encode(BTreeMap::from([(1u8, 2u8)])), hex!("c20102"));
+    }
+
+    #[test]
+    fn encode_tuples() {
+        assert_eq!(encode((0xFFu8,)), hex!("c281ff"));
+        assert_eq!(encode((0xFFu8, 0xFFu8)), hex!("c481ff81ff"));
+    }
+
+    #[test]
+    fn encode_nonzero() {
+        assert_eq!(encode(core::num::NonZeroU8::new(1).unwrap()), encode(1u8));
+    }
 }
\ No newline at end of file
@@ -16,6 +16,7 @@ pub enum Error {
     UnexpectedString = 7,
     UnexpectedList = 8,
     ListLengthMismatch(usize, usize) = 9,
+    Zero = 10,
 }
 
 
@@ -33,6 +34,7 @@ impl fmt::Display for Error {
             Error::ListLengthMismatch(expected, actual) => {
                 write!(f, "List length mismatch: expected {}, got {}", expected, actual)
             }
+            Error::Zero => write!(f, "Expected a non-zero value"),
         }
     }
 }
\ No newline at end of file
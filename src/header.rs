@@ -1,4 +1,4 @@
-use std::hint::unreachable_unchecked;
+use core::hint::unreachable_unchecked;
 
 use bytes::{Buf as _, BufMut};
 use crate::{copy_from_slice, error::{Error, Result}};
@@ -108,10 +108,13 @@ impl Header {
             let offset = if self.list() { EMPTY_LIST_CODE } else { EMPTY_STRING_CODE };
             out.put_u8(offset + payload_length as u8);
         } else {
-            let len_be = to_be_bytes_trimmed(payload_length);
+            // A big-endian `usize` is at most 8 bytes, so the trimmed length
+            // fits in a stack array and never needs to allocate.
+            let (len_be, skip) = to_be_bytes_trimmed(payload_length);
+            let len_be = &len_be[skip..];
             let offset = if self.list() { LONG_LIST_OFFSET } else { LONG_STRING_OFFSET };
             out.put_u8(offset + len_be.len() as u8);
-            out.put_slice(&len_be);
+            out.put_slice(len_be);
         }
     }
 
@@ -175,10 +178,10 @@ pub const fn length_of_length(payload_length: usize) -> usize {
 }
 
 #[inline(always)]
-fn to_be_bytes_trimmed(x: usize) -> Vec<u8> {
+fn to_be_bytes_trimmed(x: usize) -> ([u8; core::mem::size_of::<usize>()], usize) {
     let be = x.to_be_bytes();
     let skip = be.iter().take_while(|&&b| b == 0).count();
-    be[skip..].to_vec()
+    (be, skip)
 }
 
 #[inline(always)]
@@ -0,0 +1,179 @@
+//! RLP integer encoding for big-endian fixed-width integers wider than the
+//! native `u128`, e.g. the 256-bit values (account balances, storage slots)
+//! that dominate Ethereum RLP.
+//!
+//! The native integer types (`u8`..`u128`) get their `Encodable`/`Decodable`
+//! impls from the `impl_uint!`/`decode_integer!` macros in `encode.rs`/
+//! `decode.rs`. Third-party big-integer types (e.g. `ruint::Uint` or
+//! `primitive-types::U256`) can't be macro'd in the same way because this
+//! crate has no dependency on them, so instead they plug into
+//! [`encode_fixed_size`]/[`decode_fixed_size`] by implementing
+//! [`ToBeBytes`]/[`FromBeBytes`].
+
+use crate::{
+    decode::static_left_pad,
+    error::Result,
+    header::{length_of_length, Header, EMPTY_STRING_CODE},
+};
+use bytes::BufMut;
+
+/// Converts a value to its big-endian representation in an N-byte array.
+pub trait ToBeBytes<const N: usize> {
+    fn to_be_bytes(&self) -> [u8; N];
+}
+
+/// Constructs a value from its big-endian representation in an N-byte array.
+pub trait FromBeBytes<const N: usize>: Sized {
+    fn from_be_bytes(bytes: [u8; N]) -> Self;
+}
+
+/// Returns the encoded length of `value`, as computed by
+/// [`encode_fixed_size`].
+#[inline]
+pub fn length_fixed_size<T, const N: usize>(value: &T) -> usize
+where
+    T: ToBeBytes<N>,
+{
+    let be = value.to_be_bytes();
+    let skip = leading_zeros(&be);
+    if skip == N {
+        return 1;
+    }
+
+    let trimmed_len = N - skip;
+    if trimmed_len == 1 && be[skip] < EMPTY_STRING_CODE {
+        1
+    } else {
+        trimmed_len + length_of_length(trimmed_len)
+    }
+}
+
+/// Encodes `value` the same way the native uint types are encoded: leading
+/// zero bytes are trimmed, a zero value becomes [`EMPTY_STRING_CODE`], and a
+/// single byte below `0x80` is emitted raw.
+#[inline]
+pub fn encode_fixed_size<T, const N: usize>(value: &T, out: &mut dyn BufMut)
+where
+    T: ToBeBytes<N>,
+{
+    let be = value.to_be_bytes();
+    let skip = leading_zeros(&be);
+    if skip == N {
+        out.put_u8(EMPTY_STRING_CODE);
+        return;
+    }
+
+    let trimmed = &be[skip..];
+    if trimmed.len() == 1 && trimmed[0] < EMPTY_STRING_CODE {
+        out.put_u8(trimmed[0]);
+    } else {
+        // `Header::encode` picks the short- or long-form length prefix on
+        // its own, so this handles `N > 55` correctly without duplicating
+        // that threshold here.
+        Header::new(false, trimmed.len()).encode(out);
+        out.put_slice(trimmed);
+    }
+}
+
+/// Decodes a value previously written by [`encode_fixed_size`], left-padding
+/// the payload back out to N bytes. Rejects a non-canonical leading zero
+/// byte via [`Error::LeadingZero`](crate::error::Error::LeadingZero).
+#[inline]
+pub fn decode_fixed_size<T, const N: usize>(buf: &mut &[u8]) -> Result<T>
+where
+    T: FromBeBytes<N>,
+{
+    let bytes = crate::header::Header::decode_bytes(buf, false)?;
+    static_left_pad(bytes).map(T::from_be_bytes)
+}
+
+#[inline]
+fn leading_zeros<const N: usize>(be: &[u8; N]) -> usize {
+    be.iter().take_while(|&&b| b == 0).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[derive(Debug)]
+    struct U256([u8; 32]);
+
+    impl ToBeBytes<32> for U256 {
+        fn to_be_bytes(&self) -> [u8; 32] {
+            self.0
+        }
+    }
+
+    impl FromBeBytes<32> for U256 {
+        fn from_be_bytes(bytes: [u8; 32]) -> Self {
+            Self(bytes)
+        }
+    }
+
+    #[test]
+    fn roundtrip_zero() {
+        let zero = U256([0; 32]);
+        let mut out = Vec::new();
+        encode_fixed_size(&zero, &mut out);
+        assert_eq!(out, hex_literal::hex!("80"));
+        assert_eq!(length_fixed_size(&zero), out.len());
+
+        let decoded: U256 = decode_fixed_size(&mut &out[..]).unwrap();
+        assert_eq!(decoded.0, zero.0);
+    }
+
+    #[test]
+    fn roundtrip_trimmed() {
+        let mut bytes = [0u8; 32];
+        bytes[31] = 0x01;
+        let value = U256(bytes);
+
+        let mut out = Vec::new();
+        encode_fixed_size(&value, &mut out);
+        assert_eq!(out, hex_literal::hex!("01"));
+        assert_eq!(length_fixed_size(&value), out.len());
+
+        let decoded: U256 = decode_fixed_size(&mut &out[..]).unwrap();
+        assert_eq!(decoded.0, value.0);
+    }
+
+    #[test]
+    fn rejects_leading_zero() {
+        let bytes = hex_literal::hex!("8200ff");
+        let err = decode_fixed_size::<U256, 32>(&mut &bytes[..]).unwrap_err();
+        assert_eq!(err, crate::error::Error::LeadingZero);
+    }
+
+    struct Wide([u8; 64]);
+
+    impl ToBeBytes<64> for Wide {
+        fn to_be_bytes(&self) -> [u8; 64] {
+            self.0
+        }
+    }
+
+    impl FromBeBytes<64> for Wide {
+        fn from_be_bytes(bytes: [u8; 64]) -> Self {
+            Self(bytes)
+        }
+    }
+
+    #[test]
+    fn long_form_header_above_55_bytes() {
+        let mut bytes = [0xFFu8; 64];
+        bytes[0] = 0x01;
+        let value = Wide(bytes);
+
+        let mut out = Vec::new();
+        encode_fixed_size(&value, &mut out);
+        assert_eq!(length_fixed_size(&value), out.len());
+        // 64-byte payload needs the long-form string header: 0xB8 (0xB7 + 1
+        // length-of-length byte) followed by the length byte itself.
+        assert_eq!(&out[..2], &[0xB8, 64]);
+
+        let decoded: Wide = decode_fixed_size(&mut &out[..]).unwrap();
+        assert_eq!(decoded.0, value.0);
+    }
+}
@@ -1,8 +1,28 @@
+//! `#![no_std]` by default. The core `Encodable`/`Decodable` impls (integers,
+//! byte slices/arrays, tuples, `NonZero*`, ...) need no allocator at all.
+//! Build with the `alloc` feature for the `Vec`/`String`/`BTreeMap`/etc.
+//! impls and [`stream::RlpStream`], `std` to additionally get `HashMap`
+//! support, and `bytes` (on by default) for the `Bytes`/`BytesMut` impls.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod header;
 pub mod error;
 pub mod encode;
 pub mod decode;
+// `RlpStream` needs a growable stack of open lists to support arbitrary
+// nesting depth, so it's only available with an allocator.
+#[cfg(feature = "alloc")]
+pub mod stream;
+pub mod int;
+
+pub use decode::Decodable;
+pub use encode::Encodable;
 
+#[doc(hidden)]
+pub use bytes;
 
 #[inline(always)]
 pub fn copy_from_slice<T>(dst: &mut [T], src: &[T]) 
@@ -0,0 +1,245 @@
+//! Incremental RLP encoding.
+//!
+//! [`Encodable::encode`](crate::encode::Encodable::encode) requires the
+//! payload length of every list to be known up front, which means nested,
+//! heterogeneous structures either have to be pre-computed via `length()`
+//! or collected into a `Vec<Box<dyn Encodable>>`. [`RlpStream`] instead lets
+//! callers build output imperatively: open a list with [`begin_list`] or
+//! [`begin_unbounded_list`], `append` items into it as they become
+//! available, and the stream backfills the list's length prefix once the
+//! list is known to be complete.
+//!
+//! [`begin_list`]: RlpStream::begin_list
+//! [`begin_unbounded_list`]: RlpStream::begin_unbounded_list
+
+use crate::{encode::Encodable, header::{Header, EMPTY_LIST_CODE, EMPTY_STRING_CODE}};
+use alloc::vec::Vec;
+use bytes::{BufMut, BytesMut};
+
+/// Bookkeeping for a list whose header hasn't been written yet.
+struct ListInfo {
+    /// Offset into the stream's buffer where this list's payload starts.
+    position: usize,
+    /// Number of items appended into this list so far.
+    current: usize,
+    /// `Some(n)` for a `begin_list(n)` with a known item count; `None` for
+    /// a `begin_unbounded_list()`, which is closed explicitly.
+    max: Option<usize>,
+}
+
+/// An imperative RLP encoder for nested, heterogeneous lists.
+pub struct RlpStream {
+    buffer: BytesMut,
+    unfinished_lists: Vec<ListInfo>,
+}
+
+impl Default for RlpStream {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RlpStream {
+    /// Creates a new, empty stream.
+    pub fn new() -> Self {
+        Self { buffer: BytesMut::new(), unfinished_lists: Vec::new() }
+    }
+
+    /// Creates a new, empty stream with pre-allocated capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { buffer: BytesMut::with_capacity(capacity), unfinished_lists: Vec::new() }
+    }
+
+    /// Appends a single RLP-encodable value.
+    pub fn append<E: Encodable + ?Sized>(&mut self, value: &E) -> &mut Self {
+        value.encode(&mut self.buffer);
+        self.note_appended(1);
+        self
+    }
+
+    /// Appends `item_count` already-RLP-encoded items from `bytes` verbatim.
+    ///
+    /// Useful when the caller already holds a pre-encoded fragment (e.g. a
+    /// sub-list copied from elsewhere) and wants to splice it in without
+    /// re-decoding it.
+    pub fn append_raw(&mut self, bytes: &[u8], item_count: usize) -> &mut Self {
+        self.buffer.put_slice(bytes);
+        self.note_appended(item_count);
+        self
+    }
+
+    /// Appends an empty string (`0x80`), e.g. for a `None`/absent field.
+    pub fn append_empty_data(&mut self) -> &mut Self {
+        self.buffer.put_u8(EMPTY_STRING_CODE);
+        self.note_appended(1);
+        self
+    }
+
+    /// Opens a list that will contain exactly `len` items.
+    ///
+    /// The list is closed automatically once `len` items have been
+    /// appended into it; there is no matching `end_list`.
+    pub fn begin_list(&mut self, len: usize) -> &mut Self {
+        if len == 0 {
+            self.buffer.put_u8(EMPTY_LIST_CODE);
+            self.note_appended(1);
+        } else {
+            self.unfinished_lists.push(ListInfo { position: self.buffer.len(), current: 0, max: Some(len) });
+        }
+        self
+    }
+
+    /// Opens a list whose length isn't known up front. Must be closed with
+    /// [`finalize_unbounded_list`](Self::finalize_unbounded_list).
+    pub fn begin_unbounded_list(&mut self) -> &mut Self {
+        self.unfinished_lists.push(ListInfo { position: self.buffer.len(), current: 0, max: None });
+        self
+    }
+
+    /// Closes the innermost open list, which must be an unbounded one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no list is open, or if the innermost open list was opened
+    /// with [`begin_list`](Self::begin_list).
+    pub fn finalize_unbounded_list(&mut self) {
+        let list = self.unfinished_lists.pop().expect("finalize_unbounded_list: no list is open");
+        assert!(list.max.is_none(), "finalize_unbounded_list: the open list has a known length");
+        self.complete_list(list);
+        // The list we just closed counts as a single item in its parent, if any.
+        self.note_appended(1);
+    }
+
+    /// Consumes the stream, returning the encoded bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any list opened with `begin_list`/`begin_unbounded_list`
+    /// was never completed.
+    pub fn out(self) -> BytesMut {
+        assert!(self.unfinished_lists.is_empty(), "RlpStream::out: a list was never finished");
+        self.buffer
+    }
+
+    fn note_appended(&mut self, items_appended: usize) {
+        let Some(list) = self.unfinished_lists.last_mut() else { return };
+        list.current += items_appended;
+
+        let Some(max) = list.max else { return };
+        match list.current.cmp(&max) {
+            core::cmp::Ordering::Less => {}
+            core::cmp::Ordering::Equal => {
+                let list = self.unfinished_lists.pop().unwrap();
+                self.complete_list(list);
+                // The list we just closed counts as a single item in its parent.
+                self.note_appended(1);
+            }
+            core::cmp::Ordering::Greater => {
+                panic!("RlpStream: more items appended to a list than its declared length")
+            }
+        }
+    }
+
+    /// Computes the header for a now-complete list and splices it in front
+    /// of the payload that was already written for it.
+    fn complete_list(&mut self, list: ListInfo) {
+        let payload_length = self.buffer.len() - list.position;
+        let header = Header::new(true, payload_length);
+
+        // A header is at most 9 bytes (1 prefix byte + up to 8 big-endian
+        // length bytes for a `usize`), so this never needs to allocate.
+        let mut header_bytes = HeaderScratch { buf: [0; 9], len: 0 };
+        header.encode(&mut header_bytes);
+
+        let payload = self.buffer.split_off(list.position);
+        self.buffer.put_slice(header_bytes.as_slice());
+        self.buffer.unsplit(payload);
+    }
+}
+
+/// Fixed-size, non-allocating scratch space for a single encoded [`Header`].
+struct HeaderScratch {
+    buf: [u8; 9],
+    len: usize,
+}
+
+impl HeaderScratch {
+    fn as_slice(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+unsafe impl BufMut for HeaderScratch {
+    #[inline]
+    fn remaining_mut(&self) -> usize {
+        self.buf.len() - self.len
+    }
+
+    #[inline]
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        self.len += cnt;
+    }
+
+    #[inline]
+    fn chunk_mut(&mut self) -> &mut bytes::buf::UninitSlice {
+        unreachable!("HeaderScratch only supports put_u8/put_slice")
+    }
+
+    #[inline]
+    fn put_slice(&mut self, src: &[u8]) {
+        self.buf[self.len..self.len + src.len()].copy_from_slice(src);
+        self.len += src.len();
+    }
+
+    #[inline]
+    fn put_u8(&mut self, val: u8) {
+        self.buf[self.len] = val;
+        self.len += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounded_list() {
+        let mut s = RlpStream::new();
+        s.begin_list(2).append(&0xFFu8).append(&0xFFu8);
+        assert_eq!(s.out(), &hex_literal::hex!("c481ff81ff")[..]);
+    }
+
+    #[test]
+    fn empty_list() {
+        let mut s = RlpStream::new();
+        s.begin_list(0);
+        assert_eq!(s.out(), &hex_literal::hex!("c0")[..]);
+    }
+
+    #[test]
+    fn nested_unbounded_list() {
+        let mut s = RlpStream::new();
+        s.begin_list(1);
+        s.begin_unbounded_list();
+        s.append(&0xFFu8);
+        s.finalize_unbounded_list();
+        assert_eq!(s.out(), &hex_literal::hex!("c3c281ff")[..]);
+    }
+
+    #[test]
+    fn append_raw_counts_items() {
+        let mut s = RlpStream::new();
+        s.begin_list(2);
+        s.append_raw(&hex_literal::hex!("01"), 1);
+        s.append_raw(&hex_literal::hex!("02"), 1);
+        assert_eq!(s.out(), &hex_literal::hex!("c20102")[..]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unfinished_unbounded_list_panics_on_out() {
+        let mut s = RlpStream::new();
+        s.begin_unbounded_list();
+        let _ = s.out();
+    }
+}